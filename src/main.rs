@@ -1,6 +1,9 @@
 use clap::builder::PossibleValuesParser;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
 use serde_json::Value;
+use spdx::Expression;
 use std::collections::HashMap;
 use std::error::Error;
 use std::ffi::OsStr;
@@ -9,6 +12,8 @@ use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::{self, Command};
 
+mod license_text;
+
 struct LicenseFile {
     path: PathBuf,
     relative_path: String,
@@ -31,6 +36,9 @@ struct Package {
     license: Option<String>,
     license_files: Vec<LicenseFile>,
     multiple_versions: bool,
+    // populated from REUSE-style in-file SPDX headers when no license file was found
+    spdx_headers: Vec<String>,
+    copyright_texts: Vec<String>,
 }
 
 impl Package {
@@ -52,6 +60,13 @@ enum Color {
     Yellow = 33,
 }
 
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Plain,
+    Json,
+    Html,
+}
+
 // try to match output of other cargo commands
 #[derive(Debug, Parser)]
 #[command(
@@ -89,6 +104,31 @@ struct Opt {
     #[arg(hide = true, long)]
     show_url: bool,
 
+    /// Allow only the given license (can be used multiple times)
+    #[arg(long, value_name = "LICENSE")]
+    allow: Vec<String>,
+
+    /// Deny the given license (can be used multiple times)
+    #[arg(long, value_name = "LICENSE")]
+    deny: Vec<String>,
+
+    /// Warn when the declared license disagrees with the detected license file contents
+    #[arg(long)]
+    verify_license: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+
+    /// Path to a clarifications config file (defaults to `3pl.toml` in the workspace root)
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Print each license file verbatim per package instead of deduplicating identical texts
+    /// (plain format only; json and html always list one entry per package)
+    #[arg(long)]
+    no_dedup: bool,
+
     // cargo passes 3pl
     // this approach allows cargo-3pl 3pl but that's fine
     #[arg(hide = true, value_parser = PossibleValuesParser::new(&["3pl"]))]
@@ -180,10 +220,138 @@ fn get_metadata(opt: &Opt) -> Result<Value, Box<dyn Error>> {
     Ok(serde_json::from_slice(&output.stdout)?)
 }
 
+#[derive(Deserialize)]
+struct ClarificationsConfig {
+    #[serde(default, rename = "clarification")]
+    clarifications: Vec<Clarification>,
+}
+
+#[derive(Deserialize)]
+struct Clarification {
+    name: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    license: Option<String>,
+    #[serde(default, rename = "license-files")]
+    license_files: Vec<String>,
+}
+
+impl Clarification {
+    fn matches(&self, name: &str, version: &str) -> bool {
+        if self.name != name {
+            return false;
+        }
+        match &self.version {
+            Some(req) => VersionReq::parse(req)
+                .ok()
+                .zip(Version::parse(version).ok())
+                .is_some_and(|(req, version)| req.matches(&version)),
+            None => true,
+        }
+    }
+}
+
+fn find_clarifications_path(opt: &Opt, workspace_root: &Path) -> Option<PathBuf> {
+    if let Some(config) = &opt.config {
+        return Some(config.clone());
+    }
+    let default_path = workspace_root.join("3pl.toml");
+    default_path.is_file().then_some(default_path)
+}
+
+fn load_clarifications(path: &Path) -> Result<Vec<Clarification>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    let config: ClarificationsConfig = toml::from_str(&contents)?;
+    Ok(config.clarifications)
+}
+
+fn find_reuse_headers(
+    dir: &Path,
+    licenses: &mut Vec<String>,
+    copyrights: &mut Vec<String>,
+    files_scanned: &mut usize,
+    files_with_headers: &mut usize,
+) {
+    if !dir.is_dir() {
+        return;
+    }
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_reuse_headers(&path, licenses, copyrights, files_scanned, files_with_headers);
+        } else if let Ok(contents) = fs::read_to_string(&path) {
+            *files_scanned += 1;
+            let mut tagged = false;
+            for line in contents.lines() {
+                if let Some(id) = line.split("SPDX-License-Identifier:").nth(1) {
+                    let id = id.trim().to_string();
+                    if !id.is_empty() {
+                        tagged = true;
+                        if !licenses.contains(&id) {
+                            licenses.push(id);
+                        }
+                    }
+                }
+                if let Some(text) = line.split("SPDX-FileCopyrightText:").nth(1) {
+                    let text = text.trim().to_string();
+                    if !text.is_empty() && !copyrights.contains(&text) {
+                        copyrights.push(text);
+                    }
+                }
+            }
+            if tagged {
+                *files_with_headers += 1;
+            }
+        }
+    }
+}
+
+fn has_reuse_manifest(root: &Path) -> bool {
+    root.join("REUSE.toml").is_file() || root.join(".reuse").join("dep5").is_file()
+}
+
+// following the REUSE specification: when a crate has no standalone license
+// file, fall back to the SPDX identifiers and copyright lines declared in
+// per-file header comments. Only treat the crate as REUSE-compliant when it
+// declares a REUSE.toml/dep5 manifest, or when a majority of its source
+// files actually carry an SPDX-License-Identifier header, so a single
+// vendored file with an incidental tag can't fabricate whole-crate
+// attribution.
+fn find_reuse_attribution(root: &Path) -> Option<(Vec<String>, Vec<String>)> {
+    let mut licenses = Vec::new();
+    let mut copyrights = Vec::new();
+    let mut files_scanned = 0;
+    let mut files_with_headers = 0;
+    find_reuse_headers(
+        root,
+        &mut licenses,
+        &mut copyrights,
+        &mut files_scanned,
+        &mut files_with_headers,
+    );
+
+    if licenses.is_empty() && copyrights.is_empty() {
+        return None;
+    }
+
+    let majority_tagged = files_scanned > 0 && files_with_headers * 2 > files_scanned;
+    if has_reuse_manifest(root) || majority_tagged {
+        Some((licenses, copyrights))
+    } else {
+        None
+    }
+}
+
 fn find_packages(opt: &Opt) -> Result<Vec<Package>, Box<dyn Error>> {
     let metadata = get_metadata(opt)?;
     let workspace_root = metadata["workspace_root"].as_str().unwrap();
 
+    let clarifications = match find_clarifications_path(opt, Path::new(workspace_root)) {
+        Some(path) => load_clarifications(&path)?,
+        None => Vec::new(),
+    };
+
     let mut packages = Vec::new();
     for package in metadata["packages"].as_array().unwrap() {
         let manifest_path = PathBuf::from(package["manifest_path"].as_str().unwrap());
@@ -194,8 +362,8 @@ fn find_packages(opt: &Opt) -> Result<Vec<Package>, Box<dyn Error>> {
             continue;
         }
 
-        let name = package["name"].as_str().unwrap().into();
-        let version = package["version"].as_str().unwrap().into();
+        let name: String = package["name"].as_str().unwrap().into();
+        let version: String = package["version"].as_str().unwrap().into();
 
         let mut license_files = Vec::new();
         let path = manifest_path.parent().unwrap().to_path_buf();
@@ -212,6 +380,35 @@ fn find_packages(opt: &Opt) -> Result<Vec<Package>, Box<dyn Error>> {
             find_license_files(&mut license_files, &s, &s, true);
         }
 
+        let mut license: Option<String> = package["license"].as_str().map(|v| v.into());
+
+        for clarification in clarifications.iter().filter(|v| v.matches(&name, &version)) {
+            if let Some(clarified_license) = &clarification.license {
+                license = Some(clarified_license.clone());
+            }
+            for license_file in &clarification.license_files {
+                let license_path = path.join(license_file);
+                if !license_path.is_file() {
+                    return Err(format!(
+                        "Clarification for {} v{} references a license file that doesn't exist: {}",
+                        name,
+                        version,
+                        license_path.display()
+                    )
+                    .into());
+                }
+                if !license_files.iter().any(|v| v.path == license_path) {
+                    license_files.push(LicenseFile::new(license_path, &path));
+                }
+            }
+        }
+
+        let (spdx_headers, copyright_texts) = if license_files.is_empty() {
+            find_reuse_attribution(&path).unwrap_or_default()
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
         packages.push(Package {
             name,
             version,
@@ -219,9 +416,11 @@ fn find_packages(opt: &Opt) -> Result<Vec<Package>, Box<dyn Error>> {
                 .as_str()
                 .or_else(|| package["repository"].as_str())
                 .map(|v| v.into()),
-            license: package["license"].as_str().map(|v| v.into()),
+            license,
             license_files,
             multiple_versions: false,
+            spdx_headers,
+            copyright_texts,
         })
     }
 
@@ -237,11 +436,124 @@ fn find_packages(opt: &Opt) -> Result<Vec<Package>, Box<dyn Error>> {
     Ok(packages)
 }
 
+fn license_allowed(id: &str, allow: &[String], deny: &[String]) -> bool {
+    !deny.iter().any(|v| v.eq_ignore_ascii_case(id))
+        && (allow.is_empty() || allow.iter().any(|v| v.eq_ignore_ascii_case(id)))
+}
+
+// a package passes if at least one licensing path through its expression
+// is fully satisfied by allowed licenses and contains no denied license
+// crates.io still carries crates predating SPDX expressions that declare
+// their license as a `/`-separated list (e.g. "MIT/Apache-2.0"); accept that
+// legacy format by retrying as an `OR` expression before giving up
+fn parse_license_expression(license: &str) -> Result<Expression, String> {
+    Expression::parse(license).or_else(|err| {
+        if license.contains('/') {
+            Expression::parse(&license.replace('/', " OR ")).map_err(|e| e.to_string())
+        } else {
+            Err(err.to_string())
+        }
+    })
+}
+
+fn check_license_policy(
+    packages: &[Package],
+    allow: &[String],
+    deny: &[String],
+) -> Result<(), Box<dyn Error>> {
+    let mut failed = false;
+
+    for package in packages {
+        let Some(license) = &package.license else {
+            continue;
+        };
+
+        let expression = match parse_license_expression(license) {
+            Ok(v) => v,
+            Err(err) => {
+                warn(format!(
+                    "Invalid license expression: {} ({})",
+                    package.full_name(),
+                    err
+                ));
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut offender = None;
+        let satisfied = expression.evaluate(|req| {
+            let id = req
+                .license
+                .id()
+                .map(|v| v.name)
+                .unwrap_or("unknown license");
+            let ok = license_allowed(id, allow, deny);
+            if !ok && offender.is_none() {
+                offender = Some(id.to_string());
+            }
+            ok
+        });
+
+        if !satisfied {
+            warn(format!(
+                "License not allowed: {} ({})",
+                package.full_name(),
+                offender.unwrap_or_else(|| "unknown license".into())
+            ));
+            failed = true;
+        }
+    }
+
+    if failed {
+        Err("Exiting due to license policy violations".into())
+    } else {
+        Ok(())
+    }
+}
+
+fn verify_license_files(packages: &[Package]) -> Result<(), Box<dyn Error>> {
+    for package in packages {
+        for license_file in &package.license_files {
+            let text = fs::read_to_string(&license_file.path)?;
+            let Some(detected) = license_text::detect_license(&text) else {
+                continue;
+            };
+
+            let matches_declared = package
+                .license
+                .as_ref()
+                .is_some_and(|v| v.contains(&detected.id));
+
+            if !matches_declared {
+                warn(format!(
+                    "License mismatch: {} declares {:?} but {} looks like {} ({:.0}% match)",
+                    package.full_name(),
+                    package.license.as_deref().unwrap_or("no license"),
+                    license_file.relative_path,
+                    detected.id,
+                    detected.score * 100.0
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn print_header(header: String) {
     println!("{}\n{}\n{}", "=".repeat(80), header, "=".repeat(80));
 }
 
-fn print_packages(packages: &[Package]) -> Result<(), Box<dyn Error>> {
+fn print_packages(packages: &[Package], format: Format, dedup: bool) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Plain => print_plain(packages, dedup),
+        Format::Json => print_json(packages),
+        Format::Html => print_html(packages),
+    }
+}
+
+fn print_plain(packages: &[Package], dedup: bool) -> Result<(), Box<dyn Error>> {
     print_header("Summary".into());
     for package in packages {
         println!();
@@ -254,6 +566,30 @@ fn print_packages(packages: &[Package]) -> Result<(), Box<dyn Error>> {
         }
     }
 
+    if dedup {
+        print_license_groups(packages)?;
+    } else {
+        print_license_files_verbatim(packages)?;
+    }
+
+    for package in packages {
+        if package.license_files.is_empty() && !package.spdx_headers.is_empty() {
+            println!();
+            print_header(format!("{} (SPDX headers)", package.display_name()));
+            println!();
+            for id in &package.spdx_headers {
+                println!("SPDX-License-Identifier: {}", id);
+            }
+            for text in &package.copyright_texts {
+                println!("SPDX-FileCopyrightText: {}", text);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_license_files_verbatim(packages: &[Package]) -> Result<(), Box<dyn Error>> {
     let mut stdout = io::stdout();
     for package in packages {
         for license_file in &package.license_files {
@@ -281,6 +617,180 @@ fn print_packages(packages: &[Package]) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+struct LicenseGroup {
+    relative_path: String,
+    contents: String,
+    members: Vec<String>,
+}
+
+// normalize line endings and surrounding whitespace so that copies of the
+// same license that only differ in trailing newlines still dedup together
+fn normalize_for_dedup(text: &str) -> String {
+    text.lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn license_hash(text: &str) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    normalize_for_dedup(text).hash(&mut hasher);
+    hasher.finish()
+}
+
+fn group_license_files(packages: &[Package]) -> Result<Vec<LicenseGroup>, Box<dyn Error>> {
+    let mut groups: Vec<LicenseGroup> = Vec::new();
+    let mut index: HashMap<u64, usize> = HashMap::new();
+
+    for package in packages {
+        for license_file in &package.license_files {
+            let contents = fs::read_to_string(&license_file.path)?;
+            let hash = license_hash(&contents);
+            let member = package.full_name();
+
+            match index.get(&hash) {
+                Some(&i) => {
+                    if !groups[i].members.contains(&member) {
+                        groups[i].members.push(member);
+                    }
+                }
+                None => {
+                    index.insert(hash, groups.len());
+                    groups.push(LicenseGroup {
+                        relative_path: license_file.relative_path.clone(),
+                        contents,
+                        members: vec![member],
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
+fn print_license_groups(packages: &[Package]) -> Result<(), Box<dyn Error>> {
+    for group in group_license_files(packages)? {
+        println!();
+        print_header(group.relative_path);
+        println!();
+        print!("{}", group.contents);
+        if !group.contents.ends_with('\n') {
+            println!();
+        }
+        println!();
+        println!("Used by:");
+        for member in &group.members {
+            println!("  {}", member);
+        }
+    }
+
+    Ok(())
+}
+
+fn package_json(package: &Package) -> Result<Value, Box<dyn Error>> {
+    let mut license_files = Vec::new();
+    for license_file in &package.license_files {
+        let contents = fs::read_to_string(&license_file.path)?;
+        license_files.push(serde_json::json!({
+            "path": license_file.relative_path,
+            "contents": contents,
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "name": package.name,
+        "version": package.version,
+        "url": package.url,
+        "license": package.license,
+        "license_files": license_files,
+        "spdx_headers": package.spdx_headers,
+        "copyright_texts": package.copyright_texts,
+    }))
+}
+
+fn print_json(packages: &[Package]) -> Result<(), Box<dyn Error>> {
+    let mut entries = Vec::new();
+    for package in packages {
+        entries.push(package_json(package)?);
+    }
+
+    println!("{}", serde_json::to_string_pretty(&entries)?);
+
+    Ok(())
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn html_anchor(package: &Package) -> String {
+    package
+        .full_name()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn print_html(packages: &[Package]) -> Result<(), Box<dyn Error>> {
+    let mut toc = String::new();
+    let mut body = String::new();
+
+    for package in packages {
+        let anchor = html_anchor(package);
+        toc.push_str(&format!(
+            "<li><a href=\"#{}\">{}</a></li>\n",
+            anchor,
+            html_escape(&package.full_name())
+        ));
+
+        body.push_str(&format!("<section id=\"{}\">\n", anchor));
+        body.push_str(&format!("<h2>{}</h2>\n", html_escape(&package.full_name())));
+        if let Some(url) = &package.url {
+            body.push_str(&format!(
+                "<p><a href=\"{}\">{}</a></p>\n",
+                html_escape(url),
+                html_escape(url)
+            ));
+        }
+        if let Some(license) = &package.license {
+            body.push_str(&format!("<p>{}</p>\n", html_escape(license)));
+        }
+        for license_file in &package.license_files {
+            let contents = fs::read_to_string(&license_file.path)?;
+            body.push_str(&format!("<h3>{}</h3>\n", html_escape(&license_file.relative_path)));
+            body.push_str(&format!("<pre>{}</pre>\n", html_escape(&contents)));
+        }
+        if package.license_files.is_empty() && !package.spdx_headers.is_empty() {
+            body.push_str("<h3>SPDX headers</h3>\n<pre>");
+            for id in &package.spdx_headers {
+                body.push_str(&html_escape(&format!("SPDX-License-Identifier: {}\n", id)));
+            }
+            for text in &package.copyright_texts {
+                body.push_str(&html_escape(&format!("SPDX-FileCopyrightText: {}\n", text)));
+            }
+            body.push_str("</pre>\n");
+        }
+        body.push_str("</section>\n");
+    }
+
+    println!(
+        "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>Third Party Licenses</title></head>\n<body>\n<h1>Third Party Licenses</h1>\n<ul>\n{}</ul>\n{}</body>\n</html>",
+        toc, body
+    );
+
+    Ok(())
+}
+
 fn run() -> Result<(), Box<dyn Error>> {
     let opt = Opt::parse();
     let packages = find_packages(&opt)?;
@@ -297,7 +807,7 @@ fn run() -> Result<(), Box<dyn Error>> {
 
     let mut missing_files = false;
     for package in &packages {
-        if package.license_files.is_empty() {
+        if package.license_files.is_empty() && package.spdx_headers.is_empty() {
             let mut suffix = "".into();
             if opt.show_url {
                 if let Some(url) = &package.url {
@@ -316,7 +826,19 @@ fn run() -> Result<(), Box<dyn Error>> {
         return Err("Exiting due to missing license files".into());
     }
 
-    print_packages(&packages)
+    if !opt.allow.is_empty() || !opt.deny.is_empty() {
+        check_license_policy(&packages, &opt.allow, &opt.deny)?;
+    }
+
+    if opt.verify_license {
+        verify_license_files(&packages)?;
+    }
+
+    if opt.no_dedup && !matches!(opt.format, Format::Plain) {
+        warn("--no-dedup only affects plain output; json and html already list one entry per package".into());
+    }
+
+    print_packages(&packages, opt.format, !opt.no_dedup)
 }
 
 fn main() {
@@ -325,3 +847,153 @@ fn main() {
         process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_escape_neutralizes_attribute_breakout() {
+        let url = r#"" onmouseover="alert(document.domain)"#;
+        let escaped = html_escape(url);
+        assert!(!escaped.contains('"'));
+        let href = format!("<a href=\"{}\">", escaped);
+        assert!(!href.contains("onmouseover="));
+    }
+
+    fn test_package(name: &str, version: &str) -> Package {
+        Package {
+            name: name.into(),
+            version: version.into(),
+            url: Some("https://example.com".into()),
+            license: Some("MIT".into()),
+            license_files: Vec::new(),
+            multiple_versions: false,
+            spdx_headers: vec!["MIT".into()],
+            copyright_texts: vec!["2024 Example".into()],
+        }
+    }
+
+    #[test]
+    fn html_anchor_is_alphanumeric_and_stable() {
+        let anchor = html_anchor(&test_package("some-crate", "1.0.0"));
+        assert!(anchor.chars().all(|c| c.is_alphanumeric() || c == '-'));
+        assert_eq!(anchor, html_anchor(&test_package("some-crate", "1.0.0")));
+    }
+
+    #[test]
+    fn package_json_includes_declared_and_header_derived_fields() {
+        let package = test_package("some-crate", "1.0.0");
+        let value = package_json(&package).unwrap();
+        assert_eq!(value["name"], "some-crate");
+        assert_eq!(value["version"], "1.0.0");
+        assert_eq!(value["license"], "MIT");
+        assert_eq!(value["spdx_headers"][0], "MIT");
+        assert_eq!(value["copyright_texts"][0], "2024 Example");
+    }
+
+    #[test]
+    fn parse_license_expression_accepts_legacy_slash_separated_licenses() {
+        let expression = parse_license_expression("MIT/Apache-2.0").unwrap();
+        assert!(expression.evaluate(|req| req.license.id().map(|v| v.name) == Some("MIT")));
+    }
+
+    #[test]
+    fn parse_license_expression_still_rejects_garbage() {
+        assert!(parse_license_expression("not a license").is_err());
+    }
+
+    #[test]
+    fn license_allowed_respects_deny_over_allow() {
+        let allow = vec!["MIT".to_string()];
+        let deny = vec!["GPL-3.0".to_string()];
+
+        assert!(license_allowed("MIT", &allow, &deny));
+        assert!(!license_allowed("GPL-3.0", &allow, &deny));
+        assert!(!license_allowed("Apache-2.0", &allow, &deny));
+    }
+
+    #[test]
+    fn license_allowed_with_no_allow_list_permits_anything_not_denied() {
+        let deny = vec!["GPL-3.0".to_string()];
+        assert!(license_allowed("MIT", &[], &deny));
+        assert!(!license_allowed("GPL-3.0", &[], &deny));
+    }
+
+    #[test]
+    fn clarification_matches_name_and_version_req() {
+        let clarification = Clarification {
+            name: "some-crate".into(),
+            version: Some("<2.0.0".into()),
+            license: Some("MIT".into()),
+            license_files: Vec::new(),
+        };
+
+        assert!(clarification.matches("some-crate", "1.2.3"));
+        assert!(!clarification.matches("some-crate", "2.0.0"));
+        assert!(!clarification.matches("other-crate", "1.2.3"));
+    }
+
+    #[test]
+    fn clarification_without_version_matches_any_version() {
+        let clarification = Clarification {
+            name: "some-crate".into(),
+            version: None,
+            license: None,
+            license_files: Vec::new(),
+        };
+
+        assert!(clarification.matches("some-crate", "0.1.0"));
+        assert!(clarification.matches("some-crate", "9.9.9"));
+    }
+
+    #[test]
+    fn load_clarifications_parses_toml() {
+        let dir = std::env::temp_dir().join(format!("cargo3pl-clarifications-test-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("3pl.toml");
+        fs::write(
+            &path,
+            r#"
+[[clarification]]
+name = "some-crate"
+version = "1.*"
+license = "MIT"
+license-files = ["COPYING"]
+"#,
+        )
+        .unwrap();
+
+        let clarifications = load_clarifications(&path).unwrap();
+        assert_eq!(clarifications.len(), 1);
+        assert_eq!(clarifications[0].name, "some-crate");
+        assert_eq!(clarifications[0].license.as_deref(), Some("MIT"));
+        assert_eq!(clarifications[0].license_files, vec!["COPYING".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn find_reuse_attribution_requires_manifest_or_majority() {
+        let dir = std::env::temp_dir().join(format!("cargo3pl-reuse-test-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        fs::write(dir.join("a.rs"), "// SPDX-License-Identifier: MIT\n").unwrap();
+        fs::write(dir.join("b.rs"), "fn main() {}\n").unwrap();
+        fs::write(dir.join("c.rs"), "fn main() {}\n").unwrap();
+
+        // one incidentally-tagged file among several untagged ones isn't REUSE compliance
+        assert!(find_reuse_attribution(&dir).is_none());
+
+        fs::write(dir.join("REUSE.toml"), "").unwrap();
+        assert!(find_reuse_attribution(&dir).is_some());
+
+        fs::remove_file(dir.join("REUSE.toml")).unwrap();
+        fs::write(dir.join("b.rs"), "// SPDX-License-Identifier: MIT\n").unwrap();
+        assert!(find_reuse_attribution(&dir).is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}